@@ -2,11 +2,13 @@
 //! LLM Unify CLI
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand};
 use llm_unify_core::Provider;
 use llm_unify_parser::get_parser;
-use llm_unify_search::SearchEngine;
-use llm_unify_storage::{ConversationRepository, Database};
+use llm_unify_search::{Index, SearchEngine};
+use llm_unify_storage::{ConversationRepository, Database, TagRepository};
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -17,9 +19,9 @@ struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Database file path
-    #[arg(short, long, default_value = "llm-unify.db")]
-    database: PathBuf,
+    /// Database URL (sqlite://path or postgres://...)
+    #[arg(short, long, default_value = "sqlite://llm-unify.db")]
+    database: String,
 }
 
 #[derive(Subcommand)]
@@ -48,12 +50,40 @@ enum Commands {
 
     /// Search conversations
     Search {
-        /// Search query
-        query: String,
+        /// Search query (omit when using --next)
+        query: Option<String>,
 
         /// Limit results
         #[arg(short, long, default_value = "10")]
         limit: usize,
+
+        /// Fetch the next page of the previous search instead of running a new one
+        #[arg(long)]
+        next: bool,
+
+        /// Name of the search cursor to save/advance (for running multiple searches at once)
+        #[arg(long, default_value = "default")]
+        session: String,
+    },
+
+    /// Manage the full-text search index
+    Index {
+        #[command(subcommand)]
+        action: IndexAction,
+    },
+
+    /// Add or remove tags on a conversation
+    Tag {
+        /// Conversation ID
+        id: String,
+
+        /// Tags to add
+        #[arg(long = "add", value_name = "TAG")]
+        add: Vec<String>,
+
+        /// Tags to remove
+        #[arg(long = "remove", value_name = "TAG")]
+        remove: Vec<String>,
     },
 
     /// Delete a conversation
@@ -96,10 +126,52 @@ enum Commands {
     /// Launch TUI
     Tui,
 
+    /// Serve a REST admin API over the database
+    Serve {
+        /// Address to bind the HTTP server to
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        bind: SocketAddr,
+
+        /// Bearer token required for write endpoints (import/delete)
+        #[arg(long)]
+        admin_token: Option<String>,
+    },
+
     /// Show version information
     Version,
 }
 
+#[derive(Subcommand)]
+enum IndexAction {
+    /// Drop and re-add every conversation to the full-text index
+    Rebuild,
+}
+
+/// Running per-model totals accumulated while walking `Stats`.
+struct ModelStats {
+    conversation_count: usize,
+    message_count: usize,
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+}
+
+impl ModelStats {
+    fn new(seen_at: DateTime<Utc>) -> Self {
+        Self {
+            conversation_count: 0,
+            message_count: 0,
+            first_seen: seen_at,
+            last_seen: seen_at,
+        }
+    }
+
+    fn record_message(&mut self, seen_at: DateTime<Utc>) {
+        self.message_count += 1;
+        self.first_seen = self.first_seen.min(seen_at);
+        self.last_seen = self.last_seen.max(seen_at);
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -116,15 +188,20 @@ async fn main() -> Result<()> {
             let count = conversations.len();
 
             let repo = ConversationRepository::new(&db);
-            for conv in conversations {
-                repo.save(&conv).await?;
+            let index = Index::open(&db)?;
+            let mut writer = index.writer()?;
+            for conv in &conversations {
+                repo.save(conv).await?;
+                writer.add_conversation(conv)?;
             }
+            writer.commit()?;
 
             println!("Imported {} conversations from {}", count, provider);
         }
 
         Commands::List { provider } => {
             let repo = ConversationRepository::new(&db);
+            let tags = TagRepository::new(&db);
             let conversations = repo.list().await?;
 
             let filtered: Vec<_> = if let Some(p) = provider {
@@ -138,22 +215,26 @@ async fn main() -> Result<()> {
             };
 
             for conv in filtered {
+                let conv_tags = tags.tags_for(&conv.id).await?;
                 println!(
-                    "{} | {} | {} | {} messages",
+                    "{} | {} | {} | {} messages | tags: {}",
                     conv.id,
                     conv.provider,
                     conv.title,
-                    conv.message_count()
+                    conv.message_count(),
+                    conv_tags.join(", ")
                 );
             }
         }
 
         Commands::Show { id } => {
             let repo = ConversationRepository::new(&db);
+            let tags = TagRepository::new(&db);
             if let Some(conv) = repo.find_by_id(&id).await? {
                 println!("Conversation: {}", conv.title);
                 println!("Provider: {}", conv.provider);
                 println!("Messages: {}", conv.message_count());
+                println!("Tags: {}", tags.tags_for(&conv.id).await?.join(", "));
                 println!();
 
                 for msg in conv.messages {
@@ -165,17 +246,57 @@ async fn main() -> Result<()> {
             }
         }
 
-        Commands::Search { query, limit } => {
-            let search = SearchEngine::new(&db);
-            let results = search.search(&query).await?;
+        Commands::Search {
+            query,
+            limit,
+            next,
+            session,
+        } => {
+            let search = SearchEngine::new(&db)?;
+            let results = if next {
+                search.next_page(&session)?
+            } else {
+                let query = query
+                    .ok_or_else(|| anyhow::anyhow!("search query is required unless --next is given"))?;
+                search.search_page(&session, &query, limit)?
+            };
+
+            if results.is_empty() {
+                println!("No more results");
+            }
 
-            for (i, result) in results.iter().take(limit).enumerate() {
-                println!("{}. Conversation: {}", i + 1, result.conversation_id);
+            for (i, result) in results.iter().enumerate() {
+                println!(
+                    "{}. Conversation: {} (score {:.2})",
+                    i + 1,
+                    result.conversation_id,
+                    result.score
+                );
                 println!("   {}", result.snippet);
                 println!();
             }
         }
 
+        Commands::Index { action } => match action {
+            IndexAction::Rebuild => {
+                let repo = ConversationRepository::new(&db);
+                let index = Index::open(&db)?;
+                let rebuilt = index.rebuild(&repo).await?;
+                println!("Rebuilt index with {} conversations", rebuilt);
+            }
+        },
+
+        Commands::Tag { id, add, remove } => {
+            let tags = TagRepository::new(&db);
+            for tag in &add {
+                tags.add(&id, tag).await?;
+            }
+            for tag in &remove {
+                tags.remove(&id, tag).await?;
+            }
+            println!("Tags for {}: {}", id, tags.tags_for(&id).await?.join(", "));
+        }
+
         Commands::Delete { id } => {
             let repo = ConversationRepository::new(&db);
             repo.delete(&id).await?;
@@ -210,38 +331,92 @@ async fn main() -> Result<()> {
 
             // Count by provider
             let mut provider_counts = std::collections::HashMap::new();
-            for conv in conversations {
+            let mut model_stats: std::collections::HashMap<(Provider, String), ModelStats> =
+                std::collections::HashMap::new();
+            for conv in &conversations {
                 *provider_counts.entry(conv.provider).or_insert(0) += 1;
+
+                let mut seen_models = std::collections::HashSet::new();
+                for msg in &conv.messages {
+                    let Some(model) = msg.model.as_deref() else {
+                        continue;
+                    };
+                    model_stats
+                        .entry((conv.provider, model.to_string()))
+                        .or_insert_with(|| ModelStats::new(msg.created_at))
+                        .record_message(msg.created_at);
+                    seen_models.insert(model.to_string());
+                }
+                for model in seen_models {
+                    if let Some(stats) = model_stats.get_mut(&(conv.provider, model)) {
+                        stats.conversation_count += 1;
+                    }
+                }
             }
 
             println!("\nBy provider:");
             for (provider, count) in provider_counts {
                 println!("  {}: {}", provider, count);
             }
+
+            println!("\nBy model:");
+            let mut rows: Vec<_> = model_stats.into_iter().collect();
+            rows.sort_by(|((ap, am), _), ((bp, bm), _)| (ap, am).cmp(&(bp, bm)));
+            for ((provider, model), stats) in rows {
+                println!(
+                    "  {} / {}: {} conversations, {} messages, first seen {}, last seen {}",
+                    provider,
+                    model,
+                    stats.conversation_count,
+                    stats.message_count,
+                    stats.first_seen.date_naive(),
+                    stats.last_seen.date_naive()
+                );
+            }
         }
 
         Commands::Validate => {
-            println!("Database validation not yet implemented");
+            let report = db.validate().await?;
+            if report.is_valid() {
+                println!("Database OK (schema version {})", report.schema_version);
+            } else {
+                for issue in &report.issues {
+                    println!("ISSUE: {issue}");
+                }
+                anyhow::bail!("database validation failed with {} issue(s)", report.issues.len());
+            }
         }
 
         Commands::Backup { output } => {
-            std::fs::copy(&cli.database, &output)?;
+            let path = sqlite_file_path(&cli.database)?;
+            std::fs::copy(path, &output)?;
             println!("Backup created: {}", output.display());
         }
 
         Commands::Restore { input } => {
-            std::fs::copy(&input, &cli.database)?;
+            let path = sqlite_file_path(&cli.database)?;
+            std::fs::copy(&input, path)?;
             println!("Database restored from: {}", input.display());
         }
 
         Commands::Init => {
-            println!("Database initialized: {}", cli.database.display());
+            let applied = db.run_migrations().await?;
+            println!(
+                "Database initialized: {} ({} migration(s) applied)",
+                cli.database,
+                applied.len()
+            );
         }
 
         Commands::Tui => {
             llm_unify_tui::run(db).await?;
         }
 
+        Commands::Serve { bind, admin_token } => {
+            println!("Serving admin API on http://{bind}");
+            llm_unify_server::serve(db, bind, admin_token).await?;
+        }
+
         Commands::Version => {
             println!("llm-unify v{}", env!("CARGO_PKG_VERSION"));
         }
@@ -259,3 +434,19 @@ fn parse_provider(s: &str) -> Result<Provider> {
         _ => Err(anyhow::anyhow!("Unknown provider: {}", s)),
     }
 }
+
+/// Resolve a `--database` URL to a filesystem path for file-level backup/restore.
+///
+/// Only the sqlite backend can be copied as a file; Postgres databases are
+/// backed up with `pg_dump`/`pg_restore` outside of this tool.
+fn sqlite_file_path(database: &str) -> Result<PathBuf> {
+    match database.strip_prefix("sqlite://") {
+        Some(path) => Ok(PathBuf::from(path)),
+        None if database.starts_with("postgres://") || database.starts_with("postgresql://") => {
+            Err(anyhow::anyhow!(
+                "backup/restore is only supported for the sqlite backend"
+            ))
+        }
+        None => Ok(PathBuf::from(database)),
+    }
+}